@@ -4,12 +4,13 @@ use std::collections::{BTreeMap, BTreeSet};
 
 use graphshell_protocol::{
     AdvertisedAction, CachePolicy, CacheRetention, CapabilityProfile, ContentHash, NativeGlyphV1,
-    PortableCardV1, PresentationChange, PresentationCodec, PresentationManifest,
-    PresentationSemantics, ProjectionAck, ProjectionDiff, ProjectionSession, ProjectionSnapshot,
-    ResourceRequest, ResourceResponse, ResumeReply, ResumeRequest, SemanticRole, SessionStatus,
+    PortableCardV1, PresentationCapability, PresentationChange, PresentationCodec,
+    PresentationManifest, PresentationOffer, PresentationSemantics, ProjectionAck, ProjectionDiff,
+    ProjectionSession, ProjectionSnapshot, ResourceRequest, ResourceResponse, ResumeReply,
+    ResumeRequest, SemanticRole, SessionStatus,
 };
 use sceno::InstanceId;
-use scenotime::{ApplyOutcome, DiffError, SceneSnapshot, SnapshotError};
+use scenotime::{ApplyOutcome, DiffError, Revision, SceneEpoch, SceneSnapshot, SnapshotError};
 use serde::{Deserialize, Serialize};
 
 /// The local cache entry for one disclosed remote projection.
@@ -33,7 +34,7 @@ pub enum ResolvedContent {
     NativeGlyph(NativeGlyphV1),
     PortableCard(PortableCardV1),
     Image { mime_type: String, bytes: Vec<u8> },
-    LabeledPlaceholder,
+    LabeledPlaceholder { missing: PresentationCapability },
 }
 
 /// Resolution is explicit about bytes that have not arrived yet.
@@ -137,12 +138,28 @@ pub enum PersistenceError<E> {
     Store(E),
 }
 
+/// What one persisted record holds, read without mounting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PersistedSessionReport {
+    pub epoch: SceneEpoch,
+    pub revision: Revision,
+    pub item_count: usize,
+    pub relation_count: usize,
+    pub resource_count: usize,
+    /// Whether the record's cache policy would currently permit a restore
+    /// from this store.
+    pub restorable: bool,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 struct PersistedProjection {
     version: u16,
     session: ProjectionSession,
     mounted: MountedScene,
     resources: Vec<PersistedResource>,
+    // Absent from records written before pins existed.
+    #[serde(default)]
+    preferences: Vec<PersistedPreference>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -151,6 +168,12 @@ struct PersistedResource {
     bytes: Vec<u8>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct PersistedPreference {
+    instance: InstanceId,
+    capability: PresentationCapability,
+}
+
 /// Curation state. It receives scenes but never obtains source truth.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct ClientState {
@@ -158,6 +181,9 @@ pub struct ClientState {
     // Disclosure stays session-scoped even when two endpoints advertise the
     // same content hash. Cross-session reuse requires an explicit later policy.
     resources: BTreeMap<(ProjectionSession, ContentHash), Vec<u8>>,
+    // A user-pinned codec capability per item. It is local presentation
+    // preference and never travels to the endpoint.
+    preferences: BTreeMap<(ProjectionSession, u32), PresentationCapability>,
 }
 
 impl ClientState {
@@ -187,6 +213,14 @@ impl ClientState {
             .collect::<BTreeSet<_>>();
         self.resources
             .retain(|(owner, hash), _| owner != &session || advertised.contains(hash));
+        // Instance ids are only stable within one epoch.
+        if self
+            .mounted
+            .get(&session)
+            .is_some_and(|current| current.scene.epoch != mounted.scene.epoch)
+        {
+            self.preferences.retain(|(owner, _), _| owner != &session);
+        }
         self.mounted.insert(session, mounted);
         Ok(())
     }
@@ -291,7 +325,46 @@ impl ClientState {
         Ok(())
     }
 
-    /// Resolve the richest supported offer, or a semantic placeholder when no
+    /// Pin the capability whose offer should present one bound item, or clear
+    /// the pin with `None`. A pin the profile cannot honor is ignored at
+    /// resolve time rather than rejected here.
+    pub fn set_presentation_preference(
+        &mut self,
+        session: &ProjectionSession,
+        instance: InstanceId,
+        preference: Option<PresentationCapability>,
+    ) -> Result<(), ResolutionError> {
+        let mounted = self
+            .mounted
+            .get(session)
+            .ok_or(ResolutionError::UnknownSession)?;
+        if !is_bound(mounted, instance) {
+            return Err(ResolutionError::UnknownPresentation);
+        }
+        let key = (session.clone(), instance.0);
+        match preference {
+            Some(capability) => {
+                self.preferences.insert(key, capability);
+            }
+            None => {
+                self.preferences.remove(&key);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn presentation_preference(
+        &self,
+        session: &ProjectionSession,
+        instance: InstanceId,
+    ) -> Option<PresentationCapability> {
+        self.preferences
+            .get(&(session.clone(), instance.0))
+            .copied()
+    }
+
+    /// Resolve the pinned or richest supported offer, or a semantic
+    /// placeholder naming the capability the richest offer needs when no
     /// offered codec fits the client's capabilities.
     pub fn resolve(
         &self,
@@ -310,15 +383,13 @@ impl ClientState {
             .presentation
             .offers_for(instance)
             .ok_or(ResolutionError::UnknownPresentation)?;
-        let Some(offer) = offers.iter().find(|offer| profile.supports(offer.requires)) else {
-            let semantics = offers
-                .first()
-                .ok_or(ResolutionError::UnknownPresentation)?
-                .semantics
-                .clone();
+        let Some(offer) = self.select_offer(session, instance, offers, profile) else {
+            let first = offers.first().ok_or(ResolutionError::UnknownPresentation)?;
             return Ok(PresentationResolution::Ready(ResolvedPresentation {
-                semantics,
-                content: ResolvedContent::LabeledPlaceholder,
+                semantics: first.semantics.clone(),
+                content: ResolvedContent::LabeledPlaceholder {
+                    missing: first.requires,
+                },
             }));
         };
 
@@ -363,9 +434,8 @@ impl ClientState {
             let Some(offers) = mounted.presentation.offers_for(instance) else {
                 continue;
             };
-            let semantics = offers
-                .iter()
-                .find(|offer| profile.supports(offer.requires))
+            let semantics = self
+                .select_offer(session, instance, offers, profile)
                 .or_else(|| offers.first())
                 .ok_or(ResolutionError::UnknownPresentation)?
                 .semantics
@@ -383,6 +453,45 @@ impl ClientState {
         })
     }
 
+    /// Capabilities the profile lacks for items that would fall back to a
+    /// labeled placeholder, so a host can say what to enable.
+    pub fn missing_capabilities(
+        &self,
+        session: &ProjectionSession,
+        profile: &CapabilityProfile,
+    ) -> Result<BTreeSet<PresentationCapability>, ResolutionError> {
+        let mounted = self
+            .mounted
+            .get(session)
+            .ok_or(ResolutionError::UnknownSession)?;
+        let mut missing = BTreeSet::new();
+        for (instance, _) in mounted.scene.active_items_in_order() {
+            let Some(offers) = mounted.presentation.offers_for(instance) else {
+                continue;
+            };
+            if self
+                .select_offer(session, instance, offers, profile)
+                .is_none()
+            {
+                missing.extend(offers.first().map(|offer| offer.requires));
+            }
+        }
+        Ok(missing)
+    }
+
+    fn select_offer<'a>(
+        &self,
+        session: &ProjectionSession,
+        instance: InstanceId,
+        offers: &'a [PresentationOffer],
+        profile: &CapabilityProfile,
+    ) -> Option<&'a PresentationOffer> {
+        self.presentation_preference(session, instance)
+            .filter(|preferred| profile.supports(*preferred))
+            .and_then(|preferred| offers.iter().find(|offer| offer.requires == preferred))
+            .or_else(|| offers.iter().find(|offer| profile.supports(offer.requires)))
+    }
+
     pub fn acknowledgement(&self, session: &ProjectionSession) -> Option<ProjectionAck> {
         let mounted = self.mounted.get(session)?;
         Some(ProjectionAck {
@@ -486,6 +595,7 @@ impl ClientState {
     pub fn forget_session(&mut self, session: &ProjectionSession) {
         self.mounted.remove(session);
         self.resources.retain(|(owner, _), _| owner != session);
+        self.preferences.retain(|(owner, _), _| owner != session);
     }
 
     pub fn persist_session<S: ProjectionStore>(
@@ -515,11 +625,21 @@ impl ClientState {
                 bytes: bytes.clone(),
             })
             .collect();
+        let preferences = self
+            .preferences
+            .iter()
+            .filter(|((owner, _), _)| owner == session)
+            .map(|((_, instance), capability)| PersistedPreference {
+                instance: InstanceId(*instance),
+                capability: *capability,
+            })
+            .collect();
         let record = PersistedProjection {
             version: 1,
             session: session.clone(),
             mounted: mounted.clone(),
             resources,
+            preferences,
         };
         let bytes = serde_json::to_vec(&record).map_err(|_| PersistenceError::Corrupt)?;
         store.put(session, &bytes).map_err(PersistenceError::Store)
@@ -531,42 +651,86 @@ impl ClientState {
         now_ms: u64,
         store: &S,
     ) -> Result<(), PersistenceError<S::Error>> {
-        let bytes = store
-            .get(session)
-            .map_err(PersistenceError::Store)?
-            .ok_or(PersistenceError::Missing)?;
-        let record: PersistedProjection =
-            serde_json::from_slice(&bytes).map_err(|_| PersistenceError::Corrupt)?;
-        if record.version != 1 || &record.session != session {
-            return Err(PersistenceError::Corrupt);
-        }
+        let record = load_persisted(session, store)?;
         check_persistence_policy(&record.mounted.cache_policy, now_ms, store.protection())?;
-        record
+        // Live pins still name the same items when the restored epoch
+        // matches, so they replace the persisted pins, including clears.
+        let live_preferences: Option<Vec<_>> = self
             .mounted
-            .scene
-            .validate()
-            .map_err(|_| PersistenceError::Corrupt)?;
-
-        validate_presentation(&record.mounted).map_err(|_| PersistenceError::Corrupt)?;
+            .get(session)
+            .filter(|current| current.scene.epoch == record.mounted.scene.epoch)
+            .map(|_| {
+                self.preferences
+                    .iter()
+                    .filter(|((owner, _), _)| owner == session)
+                    .map(|(key, capability)| (key.clone(), *capability))
+                    .collect()
+            });
         let mut restored = ClientState::default();
         let mut restored_mounted = record.mounted.clone();
         restored_mounted.status = SessionStatus::Stale;
         restored.mounted.insert(session.clone(), restored_mounted);
         for resource in record.resources {
             restored
-                .apply_resource(ResourceResponse {
-                    session: session.clone(),
-                    resource: resource.hash,
-                    bytes: resource.bytes,
-                })
-                .map_err(|_| PersistenceError::Corrupt)?;
+                .resources
+                .insert((session.clone(), resource.hash), resource.bytes);
+        }
+        match live_preferences {
+            Some(live) => restored.preferences.extend(live),
+            None => {
+                for preference in record.preferences {
+                    restored.preferences.insert(
+                        (session.clone(), preference.instance.0),
+                        preference.capability,
+                    );
+                }
+            }
         }
         self.forget_session(session);
         self.mounted.extend(restored.mounted);
         self.resources.extend(restored.resources);
+        self.preferences.extend(restored.preferences);
         Ok(())
     }
 
+    /// Decode and validate a persisted record and report its contents. No
+    /// client state is built, so this is safe to run before choosing a store.
+    pub fn inspect_persisted<S: ProjectionStore>(
+        session: &ProjectionSession,
+        now_ms: u64,
+        store: &S,
+    ) -> Result<PersistedSessionReport, PersistenceError<S::Error>> {
+        let record = load_persisted(session, store)?;
+        let scene = &record.mounted.scene;
+        // Count only relations a host would draw: both ends must be active.
+        let active = scene
+            .active_items_in_order()
+            .into_iter()
+            .map(|(instance, _)| instance.0)
+            .collect::<BTreeSet<_>>();
+        Ok(PersistedSessionReport {
+            epoch: scene.epoch,
+            revision: scene.revision,
+            item_count: scene.active_item_count(),
+            relation_count: scene
+                .tables
+                .relations
+                .iter()
+                .flatten()
+                .filter(|relation| {
+                    active.contains(&relation.from.0) && active.contains(&relation.to.0)
+                })
+                .count(),
+            resource_count: record.resources.len(),
+            restorable: check_persistence_policy::<S::Error>(
+                &record.mounted.cache_policy,
+                now_ms,
+                store.protection(),
+            )
+            .is_ok(),
+        })
+    }
+
     pub fn remove_persisted<S: ProjectionStore>(
         session: &ProjectionSession,
         store: &mut S,
@@ -575,6 +739,60 @@ impl ClientState {
     }
 }
 
+fn load_persisted<S: ProjectionStore>(
+    session: &ProjectionSession,
+    store: &S,
+) -> Result<PersistedProjection, PersistenceError<S::Error>> {
+    let bytes = store
+        .get(session)
+        .map_err(PersistenceError::Store)?
+        .ok_or(PersistenceError::Missing)?;
+    let record: PersistedProjection =
+        serde_json::from_slice(&bytes).map_err(|_| PersistenceError::Corrupt)?;
+    if record.version != 1 || &record.session != session {
+        return Err(PersistenceError::Corrupt);
+    }
+    record
+        .mounted
+        .scene
+        .validate()
+        .map_err(|_| PersistenceError::Corrupt)?;
+    validate_presentation(&record.mounted).map_err(|_| PersistenceError::Corrupt)?;
+    // Hold persisted bytes to the same address, advertisement, and size
+    // checks as a live resource response.
+    let offers = record
+        .mounted
+        .presentation
+        .offers
+        .values()
+        .flatten()
+        .collect::<Vec<_>>();
+    for resource in &record.resources {
+        if ContentHash::of(&resource.bytes) != resource.hash
+            || !offers.iter().any(|offer| {
+                offer.resource == resource.hash && offer.byte_size == resource.bytes.len() as u64
+            })
+        {
+            return Err(PersistenceError::Corrupt);
+        }
+    }
+    if record
+        .preferences
+        .iter()
+        .any(|preference| !is_bound(&record.mounted, preference.instance))
+    {
+        return Err(PersistenceError::Corrupt);
+    }
+    Ok(record)
+}
+
+/// Whether an instance is active and has presentation offers, which is what
+/// `resolve` requires before it will present the item.
+fn is_bound(mounted: &MountedScene, instance: InstanceId) -> bool {
+    mounted.scene.active_item(instance).is_some()
+        && mounted.presentation.offers_for(instance).is_some()
+}
+
 fn validate_presentation(mounted: &MountedScene) -> Result<(), String> {
     let mut instances = BTreeSet::new();
     for binding in &mounted.presentation.bindings {
@@ -746,19 +964,29 @@ mod tests {
         );
         let mut client = ClientState::default();
         client.apply_snapshot(snapshot).unwrap();
+        let compact = CapabilityProfile::new([PresentationCapability::NativeGlyph]);
         assert_eq!(
-            client
-                .resolve(
-                    &session,
-                    InstanceId(0),
-                    &CapabilityProfile::new([PresentationCapability::NativeGlyph])
-                )
-                .unwrap(),
+            client.resolve(&session, InstanceId(0), &compact).unwrap(),
             PresentationResolution::Ready(ResolvedPresentation {
                 semantics: semantics("Map tile", SemanticRole::Image),
-                content: ResolvedContent::LabeledPlaceholder,
+                content: ResolvedContent::LabeledPlaceholder {
+                    missing: PresentationCapability::Image,
+                },
             })
         );
+        assert_eq!(
+            client.missing_capabilities(&session, &compact).unwrap(),
+            BTreeSet::from([PresentationCapability::Image])
+        );
+        assert!(
+            client
+                .missing_capabilities(
+                    &session,
+                    &CapabilityProfile::new([PresentationCapability::Image])
+                )
+                .unwrap()
+                .is_empty()
+        );
     }
 
     #[test]
@@ -988,6 +1216,186 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pinned_capability_overrides_the_richest_offer_while_supported() {
+        let session = ProjectionSession("loopback:preference".into());
+        let card = serde_json::to_vec(&PortableCardV1 {
+            title: "Card".into(),
+            values: Vec::new(),
+            badges: Vec::new(),
+            media: Vec::new(),
+        })
+        .unwrap();
+        let glyph = serde_json::to_vec(&NativeGlyphV1 {
+            label: "Glyph".into(),
+            icon: None,
+            color: None,
+        })
+        .unwrap();
+        let mut snapshot = snapshot_with_offer(
+            &session,
+            PresentationCodec::PortableCardV1,
+            PresentationCapability::PortableCard,
+            semantics("Card", SemanticRole::Article),
+            &card,
+            CacheRetention::MemoryOnly,
+        );
+        snapshot
+            .presentation
+            .offers
+            .get_mut(&PresentationKey("item:0".into()))
+            .unwrap()
+            .push(PresentationOffer {
+                codec: PresentationCodec::NativeGlyphV1,
+                resource: ContentHash::of(&glyph),
+                byte_size: glyph.len() as u64,
+                requires: PresentationCapability::NativeGlyph,
+                semantics: semantics("Glyph", SemanticRole::Graphic),
+            });
+        let mut client = ClientState::default();
+        client.apply_snapshot(snapshot).unwrap();
+        for bytes in [card, glyph] {
+            client
+                .apply_resource(ResourceResponse::new(session.clone(), bytes))
+                .unwrap();
+        }
+        let rich = CapabilityProfile::new([
+            PresentationCapability::PortableCard,
+            PresentationCapability::NativeGlyph,
+        ]);
+        let resolved = |client: &ClientState, profile: &CapabilityProfile| match client
+            .resolve(&session, InstanceId(0), profile)
+            .unwrap()
+        {
+            PresentationResolution::Ready(presentation) => presentation.content,
+            other => panic!("expected a ready presentation, got {other:?}"),
+        };
+
+        client
+            .set_presentation_preference(
+                &session,
+                InstanceId(0),
+                Some(PresentationCapability::NativeGlyph),
+            )
+            .unwrap();
+        assert!(matches!(
+            resolved(&client, &rich),
+            ResolvedContent::NativeGlyph(_)
+        ));
+        assert_eq!(
+            client.accessibility_tree(&session, &rich).unwrap().children[0].label,
+            "Glyph"
+        );
+
+        let card_only = CapabilityProfile::new([PresentationCapability::PortableCard]);
+        assert!(matches!(
+            resolved(&client, &card_only),
+            ResolvedContent::PortableCard(_)
+        ));
+
+        client
+            .set_presentation_preference(&session, InstanceId(0), None)
+            .unwrap();
+        assert!(matches!(
+            resolved(&client, &rich),
+            ResolvedContent::PortableCard(_)
+        ));
+    }
+
+    #[test]
+    fn presentation_pins_follow_epochs_and_survive_persistence() {
+        let session = ProjectionSession("loopback:pins".into());
+        let glyph = serde_json::to_vec(&NativeGlyphV1 {
+            label: "Pinned".into(),
+            icon: None,
+            color: None,
+        })
+        .unwrap();
+        let snapshot = snapshot_with_offer(
+            &session,
+            PresentationCodec::NativeGlyphV1,
+            PresentationCapability::NativeGlyph,
+            semantics("Pinned", SemanticRole::Graphic),
+            &glyph,
+            CacheRetention::Exportable,
+        );
+        let pin = Some(PresentationCapability::NativeGlyph);
+        let mut client = ClientState::default();
+        assert_eq!(
+            client.set_presentation_preference(&session, InstanceId(0), pin),
+            Err(ResolutionError::UnknownSession)
+        );
+        client.apply_snapshot(snapshot.clone()).unwrap();
+        assert_eq!(
+            client.set_presentation_preference(&session, InstanceId(9), pin),
+            Err(ResolutionError::UnknownPresentation)
+        );
+        client
+            .set_presentation_preference(&session, InstanceId(0), pin)
+            .unwrap();
+
+        client.apply_snapshot(snapshot.clone()).unwrap();
+        assert_eq!(client.presentation_preference(&session, InstanceId(0)), pin);
+
+        let mut store = MemoryStore::default();
+        client.persist_session(&session, 0, &mut store).unwrap();
+        let mut restarted = ClientState::default();
+        restarted.restore_session(&session, 0, &store).unwrap();
+        assert_eq!(
+            restarted.presentation_preference(&session, InstanceId(0)),
+            pin
+        );
+
+        let card_pin = Some(PresentationCapability::PortableCard);
+        client
+            .set_presentation_preference(&session, InstanceId(0), card_pin)
+            .unwrap();
+        client.restore_session(&session, 0, &store).unwrap();
+        assert_eq!(
+            client.presentation_preference(&session, InstanceId(0)),
+            card_pin
+        );
+        client
+            .set_presentation_preference(&session, InstanceId(0), None)
+            .unwrap();
+        client.restore_session(&session, 0, &store).unwrap();
+        assert_eq!(
+            client.presentation_preference(&session, InstanceId(0)),
+            None
+        );
+
+        let mut unbound: PersistedProjection =
+            serde_json::from_slice(&store.records[&session]).unwrap();
+        unbound.preferences[0].instance = InstanceId(9);
+        let mut unbound_store = MemoryStore::default();
+        unbound_store
+            .records
+            .insert(session.clone(), serde_json::to_vec(&unbound).unwrap());
+        assert_eq!(
+            ClientState::inspect_persisted(&session, 0, &unbound_store),
+            Err(PersistenceError::Corrupt)
+        );
+        assert_eq!(
+            ClientState::default().restore_session(&session, 0, &unbound_store),
+            Err(PersistenceError::Corrupt)
+        );
+
+        let mut next_epoch = snapshot;
+        next_epoch.scene =
+            SceneSnapshot::from_dense(SceneEpoch(2), Revision(1), scene_with_one_item()).unwrap();
+        client.apply_snapshot(next_epoch).unwrap();
+        assert_eq!(
+            client.presentation_preference(&session, InstanceId(0)),
+            None
+        );
+
+        restarted.forget_session(&session);
+        assert_eq!(
+            restarted.presentation_preference(&session, InstanceId(0)),
+            None
+        );
+    }
+
     #[derive(Default)]
     struct MemoryStore {
         encrypted: bool,
@@ -1079,6 +1487,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn inspection_reports_a_persisted_record_without_mounting_it() {
+        let session = ProjectionSession("loopback:inspect".into());
+        let glyph = serde_json::to_vec(&NativeGlyphV1 {
+            label: "Inspected".into(),
+            icon: None,
+            color: None,
+        })
+        .unwrap();
+        let hash = ContentHash::of(&glyph);
+        let mut client = ClientState::default();
+        client
+            .apply_snapshot(snapshot_with_offer(
+                &session,
+                PresentationCodec::NativeGlyphV1,
+                PresentationCapability::NativeGlyph,
+                semantics("Inspected", SemanticRole::Graphic),
+                &glyph,
+                CacheRetention::EncryptedPersistent,
+            ))
+            .unwrap();
+        client
+            .apply_resource(ResourceResponse {
+                session: session.clone(),
+                resource: hash,
+                bytes: glyph,
+            })
+            .unwrap();
+        let mut store = MemoryStore {
+            encrypted: true,
+            ..MemoryStore::default()
+        };
+        client.persist_session(&session, 10, &mut store).unwrap();
+
+        let report = ClientState::inspect_persisted(&session, 11, &store).unwrap();
+        assert_eq!(
+            report,
+            PersistedSessionReport {
+                epoch: SceneEpoch(1),
+                revision: Revision(4),
+                item_count: 1,
+                relation_count: 0,
+                resource_count: 1,
+                restorable: true,
+            }
+        );
+        store.encrypted = false;
+        assert!(
+            !ClientState::inspect_persisted(&session, 11, &store)
+                .unwrap()
+                .restorable
+        );
+        assert_eq!(
+            ClientState::inspect_persisted(
+                &ProjectionSession("loopback:absent".into()),
+                11,
+                &store
+            ),
+            Err(PersistenceError::Missing)
+        );
+    }
+
+    #[test]
+    fn inspection_and_restore_reject_the_same_tampered_resource() {
+        let session = ProjectionSession("loopback:tampered".into());
+        let glyph = serde_json::to_vec(&NativeGlyphV1 {
+            label: "Tampered".into(),
+            icon: None,
+            color: None,
+        })
+        .unwrap();
+        let mut client = ClientState::default();
+        client
+            .apply_snapshot(snapshot_with_offer(
+                &session,
+                PresentationCodec::NativeGlyphV1,
+                PresentationCapability::NativeGlyph,
+                semantics("Tampered", SemanticRole::Graphic),
+                &glyph,
+                CacheRetention::Exportable,
+            ))
+            .unwrap();
+        client
+            .apply_resource(ResourceResponse::new(session.clone(), glyph))
+            .unwrap();
+        let mut store = MemoryStore::default();
+        client.persist_session(&session, 0, &mut store).unwrap();
+        let mut changed_bytes: PersistedProjection =
+            serde_json::from_slice(&store.records[&session]).unwrap();
+        changed_bytes.resources[0].bytes.push(b'!');
+        let mut readdressed = changed_bytes.clone();
+        readdressed.resources[0].hash = ContentHash::of(&readdressed.resources[0].bytes);
+        for tampered in [changed_bytes, readdressed] {
+            store
+                .records
+                .insert(session.clone(), serde_json::to_vec(&tampered).unwrap());
+            assert_eq!(
+                ClientState::inspect_persisted(&session, 0, &store),
+                Err(PersistenceError::Corrupt)
+            );
+            let mut restored = ClientState::default();
+            assert_eq!(
+                restored.restore_session(&session, 0, &store),
+                Err(PersistenceError::Corrupt)
+            );
+            assert_eq!(restored, ClientState::default());
+        }
+    }
+
     #[test]
     fn memory_only_cache_refuses_persistence() {
         let session = ProjectionSession("loopback:memory".into());
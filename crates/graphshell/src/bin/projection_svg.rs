@@ -0,0 +1,30 @@
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    use std::path::PathBuf;
+
+    let mut args = std::env::args_os().skip(1);
+    let output = PathBuf::from(
+        args.next()
+            .expect("usage: projection_svg <output-dir> <endpoint> [endpoint...]"),
+    );
+    let endpoints: Vec<PathBuf> = args.map(PathBuf::from).collect();
+    assert!(
+        !endpoints.is_empty(),
+        "usage: projection_svg <output-dir> <endpoint> [endpoint...]"
+    );
+    let sessions = graphshell::sessions::snapshot_endpoint_processes(&endpoints)
+        .expect("Graphshell could not mount endpoint sessions");
+    std::fs::create_dir_all(&output).expect("could not create the SVG output directory");
+    for (index, session) in sessions.iter().enumerate() {
+        let Some(svg) = graphshell::view::render_projection_svg(&session.projection) else {
+            println!("skipped {}: no complete layout", session.label);
+            continue;
+        };
+        let path = output.join(format!("session-{index}.svg"));
+        std::fs::write(&path, svg).expect("could not write projection SVG");
+        println!("wrote {} to {}", session.label, path.display());
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
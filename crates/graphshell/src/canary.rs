@@ -372,7 +372,12 @@ mod tests {
             run.compact[0].content,
             ResolvedContent::NativeGlyph(_)
         ));
-        assert_eq!(run.compact[1].content, ResolvedContent::LabeledPlaceholder);
+        assert_eq!(
+            run.compact[1].content,
+            ResolvedContent::LabeledPlaceholder {
+                missing: PresentationCapability::Image
+            }
+        );
     }
 
     #[test]
@@ -22,25 +22,44 @@ pub struct SessionProjectionView {
 }
 
 /// Spawn endpoint processes, discover their projections, and mount each one
-/// through the same Graphshell client state machine.
+/// through the same Graphshell client state machine, then invoke every
+/// advertised action once so the receipt shows real intent outcomes.
 pub fn mount_endpoint_processes(
     programs: &[PathBuf],
+) -> Result<Vec<SessionProjectionView>, String> {
+    mount_programs(programs, true)
+}
+
+/// Mount endpoint projections from snapshots and resources only. No intent
+/// is sent, so endpoint-owned state is left untouched.
+pub fn snapshot_endpoint_processes(
+    programs: &[PathBuf],
+) -> Result<Vec<SessionProjectionView>, String> {
+    mount_programs(programs, false)
+}
+
+fn mount_programs(
+    programs: &[PathBuf],
+    invoke_actions: bool,
 ) -> Result<Vec<SessionProjectionView>, String> {
     let mut sessions = Vec::new();
     for program in programs {
-        sessions.extend(mount_endpoint_process(program)?);
+        sessions.extend(mount_endpoint_process(program, invoke_actions)?);
     }
     Ok(sessions)
 }
 
-fn mount_endpoint_process(program: &Path) -> Result<Vec<SessionProjectionView>, String> {
+fn mount_endpoint_process(
+    program: &Path,
+    invoke_actions: bool,
+) -> Result<Vec<SessionProjectionView>, String> {
     let mut carrier = StdioCarrier::spawn(program, std::iter::empty::<&str>())
         .map_err(|error| format!("could not start {}: {error}", program.display()))?;
     let descriptor = match carrier.request(CarrierRequestBody::Discover)? {
         CarrierResponseBody::Descriptor(descriptor) => descriptor,
         other => return Err(unexpected("descriptor", &other)),
     };
-    let views = mount_descriptor(&mut carrier, descriptor);
+    let views = mount_descriptor(&mut carrier, descriptor, invoke_actions);
     let shutdown = carrier.shutdown().map_err(|error| {
         format!(
             "endpoint {} did not stop cleanly: {error}",
@@ -57,6 +76,7 @@ fn mount_endpoint_process(program: &Path) -> Result<Vec<SessionProjectionView>,
 fn mount_descriptor(
     carrier: &mut StdioCarrier,
     descriptor: EndpointDescriptor,
+    invoke_actions: bool,
 ) -> Result<Vec<SessionProjectionView>, String> {
     let mut views = Vec::new();
     for offer in descriptor.projections {
@@ -77,7 +97,16 @@ fn mount_descriptor(
             PresentationCapability::Image,
         ]);
         let presentations = resolve_presentations(carrier, &mut client, &session, &profile)?;
-        let intents = invoke_advertised_actions(carrier, &client, &session, &presentations)?;
+        let missing = client
+            .missing_capabilities(&session, &profile)
+            .map_err(|error| format!("could not inspect {}: {error:?}", session.0))?
+            .into_iter()
+            .collect();
+        let intents = if invoke_actions {
+            invoke_advertised_actions(carrier, &client, &session, &presentations)?
+        } else {
+            Vec::new()
+        };
         views.push(SessionProjectionView {
             label: format!("{} · {}", descriptor.label, offer.label),
             projection: ProjectionReceiptView {
@@ -89,6 +118,7 @@ fn mount_descriptor(
                 ),
                 session: session.0,
                 status: format!("Live · {item_count} items"),
+                missing,
                 presentations,
                 layout: Some(layout),
                 intents,
@@ -228,6 +258,7 @@ pub fn render_session_switch_receipt(sessions: &[SessionProjectionView]) -> Stri
 *{box-sizing:border-box}body{margin:0}.switcher{position:sticky;top:0;z-index:2;display:flex;gap:8px;padding:12px;background:#0b1720;border-bottom:1px solid #314756;overflow:auto}
 button{border:1px solid #566f7e;border-radius:999px;padding:8px 13px;background:#132a37;color:#f4eedf;font:inherit;font-weight:700;white-space:nowrap}
 button[aria-selected="true"]{border-color:#d8a657;background:#2c2a22}iframe{display:block;width:100%;height:1050px;border:0;background:#071019}iframe[hidden]{display:none}
+@media(prefers-contrast:more){.switcher{border-bottom:2px solid #f4eedf}button{border:2px solid #f4eedf;font-size:1.05em}button[aria-selected="true"]{border-color:#f0c36a;background:#3a3627}}
 </style></head><body><nav class="switcher" aria-label="Projection sessions" role="tablist">
 "##,
     );
@@ -287,6 +318,7 @@ mod tests {
                 lede: "Fixture".into(),
                 session: run.session.0.clone(),
                 status: "Live".into(),
+                missing: Vec::new(),
                 presentations: run.rich.clone(),
                 layout: None,
                 intents: Vec::new(),
@@ -296,6 +328,7 @@ mod tests {
         assert_eq!(html.matches("role=\"tab\"").count(), 2);
         assert_eq!(html.matches("<iframe").count(), 2);
         assert!(html.contains("data-session=\"session-1\""));
+        assert!(html.contains("@media(prefers-contrast:more)"));
     }
 
     #[test]
@@ -2,7 +2,7 @@ use std::fmt::Write;
 
 use base64::Engine;
 use graphshell_client::{ResolvedContent, ResolvedPresentation};
-use graphshell_protocol::{AdvertisedAction, SceneSnapshot, SemanticRole};
+use graphshell_protocol::{AdvertisedAction, PresentationCapability, SceneSnapshot, SemanticRole};
 
 use crate::canary::{CanaryError, CanaryRun, run_loopback_canary};
 
@@ -93,6 +93,8 @@ pub struct ProjectionReceiptView {
     pub lede: String,
     pub session: String,
     pub status: String,
+    /// Capabilities the host lacks for items shown as labeled placeholders.
+    pub missing: Vec<PresentationCapability>,
     pub presentations: Vec<ResolvedPresentation>,
     pub layout: Option<ProjectionLayoutView>,
     pub intents: Vec<IntentReceiptView>,
@@ -109,7 +111,7 @@ pub fn render_projection_receipt(view: &ProjectionReceiptView) -> String {
 <meta name="viewport" content="width=device-width,initial-scale=1">
 <title>Graphshell projection receipt</title>
 <style>
-:root{color-scheme:dark;--ink:#f4eedf;--muted:#9eacb4;--line:#314756;--deep:#09141d;--panel:#10212c;--gold:#d8a657;--ok:#65bd85;--bad:#e46d5c}*{box-sizing:border-box}body{margin:0;min-height:100vh;background:radial-gradient(circle at 12% 0%,#193448 0,transparent 38%),linear-gradient(150deg,#071019,#0b1720 50%,#0d1b24);color:var(--ink);font:15px/1.5 Inter,ui-sans-serif,system-ui,-apple-system,"Segoe UI",sans-serif}.shell{width:min(1100px,calc(100% - 40px));margin:0 auto;padding:48px 0 64px}header{display:grid;grid-template-columns:1fr auto;gap:24px;align-items:end;margin-bottom:26px}.eyebrow{margin:0 0 9px;color:var(--gold);font-size:12px;font-weight:800;letter-spacing:.16em;text-transform:uppercase}h1{margin:0;font-size:clamp(32px,5vw,54px);letter-spacing:-.04em;line-height:1.03}.lede{max-width:720px;margin:14px 0 0;color:#b9c5ca;font-size:17px}.session{align-self:start;border:1px solid var(--line);border-radius:999px;padding:8px 13px;color:#b9c5ca;background:#0a1821;font:12px ui-monospace,SFMono-Regular,Consolas,monospace}.projection{border:1px solid var(--line);border-radius:22px;background:rgba(13,29,39,.96);box-shadow:0 18px 55px rgba(0,0,0,.25);overflow:hidden}.projection-head{display:flex;justify-content:space-between;gap:16px;padding:18px 22px;border-bottom:1px solid var(--line)}.status{color:#b9d5c5;font-size:12px}.scene{display:grid;grid-template-columns:repeat(2,minmax(0,1fr));gap:14px;padding:18px}.scene.positioned{position:relative;display:block;height:760px;padding:0;background:radial-gradient(circle at 50% 48%,rgba(44,79,99,.24),transparent 54%),linear-gradient(rgba(69,100,116,.08) 1px,transparent 1px),linear-gradient(90deg,rgba(69,100,116,.08) 1px,transparent 1px);background-size:auto,32px 32px,32px 32px}.routes{position:absolute;inset:0;width:100%;height:100%;pointer-events:none}.routes line{stroke:#527487;stroke-width:.45;vector-effect:non-scaling-stroke}.placed{position:absolute;width:42%;transform:translate(-50%,-50%);z-index:1}.placed .item{box-shadow:0 14px 35px rgba(0,0,0,.28)}.item{position:relative;min-width:0;min-height:176px;border:1px solid #38505f;border-radius:16px;background:var(--panel);overflow:hidden}.card{padding:21px}.card-top{display:flex;justify-content:space-between;gap:14px}.card-kicker{color:#79a9be;font-size:11px;font-weight:800;letter-spacing:.13em;text-transform:uppercase}.card h3,.glyph h3,.placeholder h3{margin:4px 0 14px;font-size:21px}.badges,.actions{display:flex;flex-wrap:wrap;gap:7px}.badge{border:1px solid #415b6b;border-radius:999px;padding:4px 8px;color:#c4d2d8;background:#132a37;font-size:11px}dl{display:grid;grid-template-columns:repeat(2,minmax(0,1fr));gap:12px;margin:18px 0}dl div{border-left:2px solid #34566a;padding-left:10px}dt{color:var(--muted);font-size:11px;text-transform:uppercase}dd{margin:3px 0 0;font-weight:650;overflow-wrap:anywhere}.glyph{display:grid;grid-template-columns:64px 1fr;gap:15px;align-items:center;padding:24px}.glyph-mark{display:grid;place-items:center;width:64px;height:64px;border:1px solid #7a653e;border-radius:20px;color:var(--gold);font-size:32px}.image{display:flex;flex-direction:column}.image img{display:block;width:100%;height:176px;object-fit:cover}.image-meta{display:flex;justify-content:space-between;gap:12px;padding:12px 14px}.placeholder{display:grid;place-items:center;text-align:center;padding:26px}.placeholder-mark{font-size:32px;color:#718795}button{border:1px solid #566f7e;border-radius:10px;padding:8px 11px;background:#1a3443;color:var(--ink);font:inherit;font-size:12px;font-weight:700}.receipts{display:grid;grid-template-columns:repeat(2,minmax(0,1fr));gap:12px;margin-top:18px}.receipt{border:1px solid var(--line);border-radius:14px;padding:15px;background:#0b1922}.receipt strong{display:block}.receipt p{margin:5px 0 0;color:var(--muted);font-size:13px}.accepted{color:var(--ok)}.rejected{color:var(--bad)}@media(max-width:720px){.shell{width:min(100% - 24px,620px);padding-top:28px}header{grid-template-columns:1fr}.session{justify-self:start}.scene,.receipts{grid-template-columns:1fr}.scene.positioned{display:grid;height:auto;padding:18px;background:transparent}.scene.positioned .routes{display:none}.scene.positioned .placed{position:relative;left:auto!important;top:auto!important;width:auto;transform:none}.scene.positioned .placed .item{box-shadow:none}}
+:root{color-scheme:dark;--ink:#f4eedf;--muted:#9eacb4;--line:#314756;--deep:#09141d;--panel:#10212c;--gold:#d8a657;--ok:#65bd85;--bad:#e46d5c}*{box-sizing:border-box}body{margin:0;min-height:100vh;background:radial-gradient(circle at 12% 0%,#193448 0,transparent 38%),linear-gradient(150deg,#071019,#0b1720 50%,#0d1b24);color:var(--ink);font:15px/1.5 Inter,ui-sans-serif,system-ui,-apple-system,"Segoe UI",sans-serif}.shell{width:min(1100px,calc(100% - 40px));margin:0 auto;padding:48px 0 64px}header{display:grid;grid-template-columns:1fr auto;gap:24px;align-items:end;margin-bottom:26px}.eyebrow{margin:0 0 9px;color:var(--gold);font-size:12px;font-weight:800;letter-spacing:.16em;text-transform:uppercase}h1{margin:0;font-size:clamp(32px,5vw,54px);letter-spacing:-.04em;line-height:1.03}.lede{max-width:720px;margin:14px 0 0;color:#b9c5ca;font-size:17px}.session{align-self:start;border:1px solid var(--line);border-radius:999px;padding:8px 13px;color:#b9c5ca;background:#0a1821;font:12px ui-monospace,SFMono-Regular,Consolas,monospace}.projection{border:1px solid var(--line);border-radius:22px;background:rgba(13,29,39,.96);box-shadow:0 18px 55px rgba(0,0,0,.25);overflow:hidden}.projection-head{display:flex;justify-content:space-between;gap:16px;padding:18px 22px;border-bottom:1px solid var(--line)}.status{color:#b9d5c5;font-size:12px}.scene{display:grid;grid-template-columns:repeat(2,minmax(0,1fr));gap:14px;padding:18px}.scene.positioned{position:relative;display:block;height:760px;padding:0;background:radial-gradient(circle at 50% 48%,rgba(44,79,99,.24),transparent 54%),linear-gradient(rgba(69,100,116,.08) 1px,transparent 1px),linear-gradient(90deg,rgba(69,100,116,.08) 1px,transparent 1px);background-size:auto,32px 32px,32px 32px}.routes{position:absolute;inset:0;width:100%;height:100%;pointer-events:none}.routes line{stroke:#527487;stroke-width:.45;vector-effect:non-scaling-stroke}.placed{position:absolute;width:42%;transform:translate(-50%,-50%);z-index:1}.placed .item{box-shadow:0 14px 35px rgba(0,0,0,.28)}.item{position:relative;min-width:0;min-height:176px;border:1px solid #38505f;border-radius:16px;background:var(--panel);overflow:hidden}.card{padding:21px}.card-top{display:flex;justify-content:space-between;gap:14px}.card-kicker{color:#79a9be;font-size:11px;font-weight:800;letter-spacing:.13em;text-transform:uppercase}.card h3,.glyph h3,.placeholder h3{margin:4px 0 14px;font-size:21px}.badges,.actions{display:flex;flex-wrap:wrap;gap:7px}.badge{border:1px solid #415b6b;border-radius:999px;padding:4px 8px;color:#c4d2d8;background:#132a37;font-size:11px}dl{display:grid;grid-template-columns:repeat(2,minmax(0,1fr));gap:12px;margin:18px 0}dl div{border-left:2px solid #34566a;padding-left:10px}dt{color:var(--muted);font-size:11px;text-transform:uppercase}dd{margin:3px 0 0;font-weight:650;overflow-wrap:anywhere}.glyph{display:grid;grid-template-columns:64px 1fr;gap:15px;align-items:center;padding:24px}.glyph-mark{display:grid;place-items:center;width:64px;height:64px;border:1px solid #7a653e;border-radius:20px;color:var(--gold);font-size:32px}.image{display:flex;flex-direction:column}.image img{display:block;width:100%;height:176px;object-fit:cover}.image-meta{display:flex;justify-content:space-between;gap:12px;padding:12px 14px}.placeholder{display:grid;place-items:center;text-align:center;padding:26px}.placeholder-mark{font-size:32px;color:#718795}button{border:1px solid #566f7e;border-radius:10px;padding:8px 11px;background:#1a3443;color:var(--ink);font:inherit;font-size:12px;font-weight:700}.receipts{display:grid;grid-template-columns:repeat(2,minmax(0,1fr));gap:12px;margin-top:18px}.receipt{border:1px solid var(--line);border-radius:14px;padding:15px;background:#0b1922}.receipt strong{display:block}.receipt p{margin:5px 0 0;color:var(--muted);font-size:13px}.accepted{color:var(--ok)}.rejected{color:var(--bad)}@media(max-width:720px){.shell{width:min(100% - 24px,620px);padding-top:28px}header{grid-template-columns:1fr}.session{justify-self:start}.scene,.receipts{grid-template-columns:1fr}.scene.positioned{display:grid;height:auto;padding:18px;background:transparent}.scene.positioned .routes{display:none}.scene.positioned .placed{position:relative;left:auto!important;top:auto!important;width:auto;transform:none}.scene.positioned .placed .item{box-shadow:none}}@media(prefers-contrast:more){:root{--muted:#d6dfe3;--line:#9db6c4}.lede,.session,.status{color:var(--ink)}.routes line{stroke:#c9dde8;stroke-width:1.4}.projection,.item,.receipt{border:2px solid var(--ink)}dl div{border-left-width:4px}button{border:2px solid var(--ink);font-size:14px}.card h3,.glyph h3,.placeholder h3{font-size:24px}dt,.badge{font-size:13px}}
 </style>
 </head>
 <body>
@@ -134,27 +136,21 @@ pub fn render_projection_receipt(view: &ProjectionReceiptView) -> String {
     } else {
         "scene"
     };
+    let mut status = view.status.clone();
+    if !view.missing.is_empty() {
+        let names: Vec<_> = view.missing.iter().copied().map(capability_name).collect();
+        write!(status, " · Unavailable: {}", names.join(", ")).unwrap();
+    }
     write!(
         html,
         "<section class=\"projection\"><div class=\"projection-head\"><strong>Disclosed scene</strong><span class=\"status\">{}</span></div><div class=\"{}\">",
-        escape(&view.status),
+        escape(&status),
         scene_class,
     )
     .unwrap();
     if let (Some(layout), Some(positions)) = (&view.layout, &normalized) {
         html.push_str("<svg class=\"routes\" viewBox=\"0 0 100 100\" preserveAspectRatio=\"none\" aria-hidden=\"true\">");
-        for relation in &layout.relations {
-            let (Some(from), Some(to)) = (positions.get(relation.from), positions.get(relation.to))
-            else {
-                continue;
-            };
-            write!(
-                html,
-                "<line x1=\"{:.3}\" y1=\"{:.3}\" x2=\"{:.3}\" y2=\"{:.3}\"></line>",
-                from.0, from.1, to.0, to.1
-            )
-            .unwrap();
-        }
+        write_routes(&mut html, layout, positions);
         html.push_str("</svg>");
         for (presentation, (x, y)) in view.presentations.iter().zip(positions) {
             write!(
@@ -191,6 +187,23 @@ pub fn render_projection_receipt(view: &ProjectionReceiptView) -> String {
     html
 }
 
+/// Write one `<line>` per relation whose endpoints are both placed, in the
+/// normalized frame shared by the receipt and SVG export.
+fn write_routes(out: &mut String, layout: &ProjectionLayoutView, positions: &[(f32, f32)]) {
+    for relation in &layout.relations {
+        let (Some(from), Some(to)) = (positions.get(relation.from), positions.get(relation.to))
+        else {
+            continue;
+        };
+        write!(
+            out,
+            "<line x1=\"{:.3}\" y1=\"{:.3}\" x2=\"{:.3}\" y2=\"{:.3}\"></line>",
+            from.0, from.1, to.0, to.1
+        )
+        .unwrap();
+    }
+}
+
 fn normalized_positions(placements: &[ScenePlacementView]) -> Vec<(f32, f32)> {
     let finite: Vec<_> = placements
         .iter()
@@ -240,6 +253,44 @@ fn normalize_axis(value: f32, min: f32, span: f32, low: f32, high: f32) -> f32 {
     }
 }
 
+/// Export a positioned projection as a standalone SVG document in the same
+/// normalized frame as the receipt view. Returns `None` when the view has no
+/// layout covering every presentation.
+pub fn render_projection_svg(view: &ProjectionReceiptView) -> Option<String> {
+    let layout = view.layout.as_ref()?;
+    if layout.placements.len() != view.presentations.len() {
+        return None;
+    }
+    let positions = normalized_positions(&layout.placements);
+    let mut svg = String::from(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 100 100\" font-family=\"Inter,ui-sans-serif,system-ui,sans-serif\">",
+    );
+    write!(
+        svg,
+        "<title>{}</title><rect width=\"100\" height=\"100\" fill=\"#09141d\"></rect><g stroke=\"#527487\" stroke-width=\".3\">",
+        escape(&view.title)
+    )
+    .unwrap();
+    write_routes(&mut svg, layout, &positions);
+    svg.push_str("</g>");
+    for (presentation, (x, y)) in view.presentations.iter().zip(&positions) {
+        let fill = match &presentation.content {
+            ResolvedContent::NativeGlyph(glyph) => glyph.color.as_deref().unwrap_or("#d8a657"),
+            _ => "#d8a657",
+        };
+        write!(
+            svg,
+            "<circle cx=\"{x:.3}\" cy=\"{y:.3}\" r=\"1.6\" fill=\"{}\"></circle><text x=\"{x:.3}\" y=\"{:.3}\" fill=\"#f4eedf\" font-size=\"2.4\" text-anchor=\"middle\">{}</text>",
+            escape(fill),
+            y + 4.2,
+            escape(&presentation.semantics.label)
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>\n");
+    Some(svg)
+}
+
 pub fn render_g1_receipt() -> Result<String, CanaryError> {
     run_loopback_canary().map(|run| render_canary_html(&run))
 }
@@ -365,13 +416,14 @@ fn render_item(html: &mut String, presentation: &ResolvedPresentation) {
             render_actions(html, &presentation.semantics.actions);
             html.push_str("</div></div>");
         }
-        ResolvedContent::LabeledPlaceholder => {
+        ResolvedContent::LabeledPlaceholder { missing } => {
             write!(
                 html,
-                "<div class=\"item placeholder\" role=\"{}\" aria-label=\"{}\"><div><div class=\"placeholder-mark\" aria-hidden=\"true\">◇</div><h3>{}</h3><p>Image capability unavailable. The disclosed label and action remain.</p>",
+                "<div class=\"item placeholder\" role=\"{}\" aria-label=\"{}\"><div><div class=\"placeholder-mark\" aria-hidden=\"true\">◇</div><h3>{}</h3><p>{} capability unavailable. The disclosed label and action remain.</p>",
                 role,
                 escape(&presentation.semantics.label),
-                escape(&presentation.semantics.label)
+                escape(&presentation.semantics.label),
+                capability_name(*missing)
             )
             .unwrap();
             render_actions(html, &presentation.semantics.actions);
@@ -398,6 +450,14 @@ fn render_actions(html: &mut String, actions: &[AdvertisedAction]) {
     html.push_str("</div>");
 }
 
+fn capability_name(capability: PresentationCapability) -> &'static str {
+    match capability {
+        PresentationCapability::NativeGlyph => "Native glyph",
+        PresentationCapability::PortableCard => "Portable card",
+        PresentationCapability::Image => "Image",
+    }
+}
+
 fn semantic_role(role: SemanticRole) -> &'static str {
     match role {
         SemanticRole::Graphic => "img",
@@ -450,6 +510,7 @@ mod tests {
             lede: "Endpoint-owned truth.".into(),
             session: run.session.0,
             status: "Live".into(),
+            missing: Vec::new(),
             presentations: run.rich,
             layout: None,
             intents: vec![IntentReceiptView {
@@ -460,6 +521,109 @@ mod tests {
         });
         assert!(html.contains("Portable card"));
         assert!(html.contains("class=\"accepted\">Accepted"));
+        assert!(html.contains("@media(prefers-contrast:more)"));
+    }
+
+    #[test]
+    fn projection_head_reports_unavailable_capabilities() {
+        let run = run_loopback_canary().unwrap();
+        let mut view = ProjectionReceiptView {
+            eyebrow: "Graphshell".into(),
+            title: "Compact projection".into(),
+            lede: "Endpoint-owned truth.".into(),
+            session: run.session.0,
+            status: "Live".into(),
+            missing: vec![PresentationCapability::Image],
+            presentations: run.compact,
+            layout: None,
+            intents: Vec::new(),
+        };
+        assert!(
+            render_projection_receipt(&view)
+                .contains("<span class=\"status\">Live · Unavailable: Image</span>")
+        );
+        view.missing.clear();
+        assert!(render_projection_receipt(&view).contains("<span class=\"status\">Live</span>"));
+    }
+
+    #[test]
+    fn placeholder_names_the_missing_capability() {
+        let run = run_loopback_canary().unwrap();
+        let presentation = ResolvedPresentation {
+            semantics: run.rich[0].semantics.clone(),
+            content: ResolvedContent::LabeledPlaceholder {
+                missing: PresentationCapability::PortableCard,
+            },
+        };
+        let mut html = String::new();
+        render_item(&mut html, &presentation);
+        assert!(html.contains("class=\"item placeholder\""));
+        assert!(html.contains("Portable card capability unavailable"));
+        assert!(html.contains(&escape(&presentation.semantics.label)));
+    }
+
+    #[test]
+    fn high_contrast_overrides_thicken_routes_and_enlarge_headings() {
+        let run = run_loopback_canary().unwrap();
+        let html = render_projection_receipt(&ProjectionReceiptView {
+            eyebrow: "Graphshell".into(),
+            title: "Live projection".into(),
+            lede: "Endpoint-owned truth.".into(),
+            session: run.session.0,
+            status: "Live".into(),
+            missing: Vec::new(),
+            presentations: run.rich,
+            layout: None,
+            intents: Vec::new(),
+        });
+        let (defaults, contrast) = html.split_once("@media(prefers-contrast:more)").unwrap();
+        let routes = ".routes line{";
+        let headings = ".card h3,.glyph h3,.placeholder h3{";
+        assert_eq!(declared(defaults, routes, "stroke-width"), 0.45);
+        assert_eq!(declared(contrast, routes, "stroke-width"), 1.4);
+        assert_eq!(declared(defaults, headings, "font-size"), 21.0);
+        assert_eq!(declared(contrast, headings, "font-size"), 24.0);
+    }
+
+    fn declared(css: &str, rule: &str, property: &str) -> f32 {
+        let body = css.split_once(rule).unwrap().1;
+        let body = &body[..body.find('}').unwrap()];
+        body.split(';')
+            .find_map(|declaration| declaration.strip_prefix(property)?.strip_prefix(':'))
+            .unwrap()
+            .trim_end_matches("px")
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn svg_export_draws_each_placement_and_relation_once() {
+        let run = run_loopback_canary().unwrap();
+        let mut view = ProjectionReceiptView {
+            eyebrow: "Graphshell".into(),
+            title: "Exported <projection>".into(),
+            lede: "Endpoint-owned truth.".into(),
+            session: run.session.0,
+            status: "Live".into(),
+            missing: Vec::new(),
+            presentations: run.rich,
+            layout: None,
+            intents: Vec::new(),
+        };
+        assert!(render_projection_svg(&view).is_none());
+
+        view.layout = Some(ProjectionLayoutView {
+            placements: vec![
+                ScenePlacementView { x: 0.0, y: 0.0 },
+                ScenePlacementView { x: 10.0, y: 5.0 },
+            ],
+            relations: vec![SceneRelationView { from: 0, to: 1 }],
+        });
+        let svg = render_projection_svg(&view).unwrap();
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert_eq!(svg.matches("<line").count(), 1);
+        assert!(svg.contains("<title>Exported &lt;projection&gt;</title>"));
+        assert!(svg.contains(">Coastal map tile</text>"));
     }
 
     #[test]